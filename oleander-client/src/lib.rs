@@ -0,0 +1,1092 @@
+//! Typed client for the oleander user service.
+//!
+//! Request and response types mirror the JSON the server's handlers accept and
+//! return, and [`routes`] the paths it registers. Both live next to the server in
+//! this repository and are changed in the same commit as the handlers they follow.
+
+use derive_more::Display;
+use reqwest::{header, Method, RequestBuilder, Response, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// Response header carrying a replacement bearer token; see [`Versioned::session_token`].
+pub const SESSION_TOKEN_HEADER: &str = "x-session-token";
+
+/// Paths as registered in the server's `App`.
+pub mod routes {
+    pub const USERS: &str = "/users";
+    pub const USERS_IMPORT: &str = "/users/import";
+    pub const USERS_LOOKUP: &str = "/users/lookup";
+    pub const USERS_SEARCH: &str = "/users/search";
+    pub const USERS_CHANGES: &str = "/users/changes";
+    pub const USERNAMES_CHECK: &str = "/usernames/check";
+    pub const STATS_PUBLIC: &str = "/stats/public";
+    pub const AUDIT: &str = "/audit";
+    pub const AUDIT_SUMMARIES: &str = "/audit/summaries";
+    pub const BOOTSTRAP: &str = "/bootstrap";
+    pub const LOGIN: &str = "/login";
+    pub const LOGIN_STEP_UP: &str = "/login/step-up";
+    pub const ME: &str = "/me";
+    pub const ME_PROFILE_COMPLETENESS: &str = "/me/profile/completeness";
+    pub const ONBOARDING: &str = "/onboarding";
+    pub const ADMIN_ROLE_CHANGES: &str = "/admin/role-changes";
+    pub const ADMIN_SERVICE_ACCOUNTS: &str = "/admin/service-accounts";
+    pub const ADMIN_REGIONS_USERS: &str = "/admin/regions/users";
+    pub const ADMIN_WEBHOOK_EVENTS: &str = "/admin/webhooks/events";
+    pub const ADMIN_TEMPLATES: &str = "/admin/templates";
+    pub const SERVICE_ACCOUNTS_TOKEN: &str = "/service-accounts/token";
+
+    /// Percent-encodes `value` for use as one path segment, leaving only RFC 3986
+    /// unreserved characters as they are, so a `/`, `?` or `%` in a username cannot
+    /// change which route is hit.
+    pub fn segment(value: &str) -> String {
+        let mut encoded = String::with_capacity(value.len());
+        for byte in value.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                encoded.push(byte as char);
+            } else {
+                encoded.push_str(&format!("%{:02X}", byte));
+            }
+        }
+        encoded
+    }
+
+    pub fn user(username: &str) -> String {
+        format!("{}/{}", USERS, segment(username))
+    }
+
+    pub fn user_restore(username: &str) -> String {
+        format!("{}/{}/restore", USERS, segment(username))
+    }
+
+    pub fn user_labels(username: &str) -> String {
+        format!("{}/{}/labels", USERS, segment(username))
+    }
+
+    pub fn user_label(username: &str, label: &str) -> String {
+        format!("{}/{}/labels/{}", USERS, segment(username), segment(label))
+    }
+
+    pub fn user_role(username: &str) -> String {
+        format!("{}/{}/role", USERS, segment(username))
+    }
+
+    pub fn user_quota(username: &str) -> String {
+        format!("{}/{}/quota", USERS, segment(username))
+    }
+
+    pub fn role_change_approve(id: i64) -> String {
+        format!("{}/{}/approve", ADMIN_ROLE_CHANGES, id)
+    }
+
+    pub fn role_change_reject(id: i64) -> String {
+        format!("{}/{}/reject", ADMIN_ROLE_CHANGES, id)
+    }
+
+    pub fn template(name: &str, locale: &str) -> String {
+        format!("{}/{}/{}", ADMIN_TEMPLATES, segment(name), segment(locale))
+    }
+
+    pub fn template_versions(name: &str, locale: &str) -> String {
+        format!(
+            "{}/{}/{}/versions",
+            ADMIN_TEMPLATES,
+            segment(name),
+            segment(locale)
+        )
+    }
+
+    pub fn template_render(name: &str) -> String {
+        format!("{}/{}/render", ADMIN_TEMPLATES, segment(name))
+    }
+
+    pub fn service_account(name: &str) -> String {
+        format!("{}/{}", ADMIN_SERVICE_ACCOUNTS, segment(name))
+    }
+
+    pub fn service_account_rotate(name: &str) -> String {
+        format!("{}/{}/rotate", ADMIN_SERVICE_ACCOUNTS, segment(name))
+    }
+
+    pub fn onboarding(id: &str) -> String {
+        format!("{}/{}", ONBOARDING, segment(id))
+    }
+
+    pub fn onboarding_steps(id: &str) -> String {
+        format!("{}/{}/steps", ONBOARDING, segment(id))
+    }
+
+    pub fn onboarding_complete(id: &str) -> String {
+        format!("{}/{}/complete", ONBOARDING, segment(id))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn segment_keeps_unreserved_characters() {
+            assert_eq!(segment("alice.smith-2_x~"), "alice.smith-2_x~");
+        }
+
+        #[test]
+        fn segment_encodes_delimiters_and_non_ascii() {
+            assert_eq!(segment("a/b"), "a%2Fb");
+            assert_eq!(segment("a?b#c"), "a%3Fb%23c");
+            assert_eq!(segment("50%"), "50%25");
+            assert_eq!(segment("a b+c"), "a%20b%2Bc");
+            assert_eq!(segment("zoë"), "zo%C3%AB");
+        }
+
+        #[test]
+        fn helpers_encode_every_caller_supplied_segment() {
+            assert_eq!(user("a/b"), "/users/a%2Fb");
+            assert_eq!(
+                user_label("bob", "vip/gold"),
+                "/users/bob/labels/vip%2Fgold"
+            );
+            assert_eq!(
+                template("welcome mail", "en-US"),
+                "/admin/templates/welcome%20mail/en-US"
+            );
+            assert_eq!(onboarding_complete("x?y"), "/onboarding/x%3Fy/complete");
+            assert_eq!(role_change_approve(7), "/admin/role-changes/7/approve");
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Credentials {
+    pub username: String,
+    pub pwd: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Reauthenticate {
+    pub pwd: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TokenResponse {
+    pub token: String,
+    pub token_type: String,
+    pub expires_in: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateUser {
+    pub username: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub pwd: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Keeps the user in this configured region's database.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+/// One screen of an onboarding flow. Steps may be sent in any order, and again to
+/// replace an earlier answer, until the flow is completed.
+#[derive(Debug, Serialize)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum OnboardingStep {
+    Account {
+        username: String,
+    },
+    Name {
+        first_name: String,
+        last_name: String,
+    },
+    Contact {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        email: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OnboardingFlow {
+    pub id: String,
+    /// Accepted steps by name, as last sent.
+    pub steps: serde_json::Value,
+    /// `None` once every step is in and the flow can be completed.
+    pub next_step: Option<String>,
+    pub expires_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CompleteOnboarding {
+    pub pwd: String,
+}
+
+/// Body of `PATCH /users/{username}`; `None` fields are left unchanged.
+#[derive(Debug, Default, Serialize)]
+pub struct UserUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pwd: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserResponse {
+    pub username: String,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserSummary {
+    pub username: String,
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Set only on soft-deleted users read with `include_deleted`.
+    #[serde(default)]
+    pub deleted_at: Option<String>,
+}
+
+/// Returned by [`Client::delete_me`]. The account stays usable until `deletes_at`.
+#[derive(Debug, Deserialize)]
+pub struct ScheduledDeletion {
+    pub username: String,
+    pub deletes_at: String,
+    /// A signed link that cancels the deletion, also sent in the confirmation mail.
+    pub cancel_url: String,
+    pub confirmation_sent: bool,
+}
+
+/// Returned by [`Client::profile_completeness`].
+#[derive(Debug, Deserialize)]
+pub struct ProfileCompleteness {
+    pub username: String,
+    /// The latest profiling stage the account has reached, if any.
+    pub stage: Option<String>,
+    pub complete: bool,
+    /// Required by now and not filled in, e.g. `email` or `profile.phone`.
+    pub missing: Vec<String>,
+    pub fields: Vec<ProfileFieldStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProfileFieldStatus {
+    pub field: String,
+    pub present: bool,
+    pub required: bool,
+    pub stage: String,
+    pub required_at: String,
+}
+
+/// A user together with the `ETag` version it was read at, for use with
+/// [`Client::update_user`], and its `Last-Modified` time as sent by the server.
+#[derive(Debug)]
+pub struct Versioned<T> {
+    pub value: T,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// A replacement bearer token, sent when the request ended the caller's
+    /// session (changing their own password does). The old token stops working.
+    pub session_token: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct UserListQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<String>,
+    /// `username` or `last_name`, with a `-` prefix for descending.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// Admin only: also list soft-deleted users.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub include_deleted: bool,
+    #[serde(skip)]
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserPage {
+    pub users: Vec<UserSummary>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+/// Body format for [`Client::import_users`].
+#[derive(Clone, Copy, Debug)]
+pub enum ImportFormat {
+    Ndjson,
+    Csv,
+}
+
+impl ImportFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ImportFormat::Ndjson => "application/x-ndjson",
+            ImportFormat::Csv => "text/csv",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FieldError {
+    pub field: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportRowError {
+    pub line: i64,
+    pub username: Option<String>,
+    pub code: String,
+    pub message: String,
+    #[serde(default)]
+    pub fields: Vec<FieldError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportSummary {
+    pub received: u64,
+    pub imported: u64,
+    pub rejected: u64,
+    pub errors: Vec<ImportRowError>,
+    pub errors_truncated: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LookupRequest {
+    pub usernames: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LookupResponse {
+    pub users: Vec<UserSummary>,
+    pub missing: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchHit {
+    #[serde(flatten)]
+    pub user: UserSummary,
+    pub rank: f32,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Labels {
+    pub labels: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuotaUsage {
+    pub used: u64,
+    /// `None` when unlimited.
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserQuotas {
+    pub profile_bytes: QuotaUsage,
+    pub labels: QuotaUsage,
+}
+
+/// An event type inbound webhooks accept.
+#[derive(Debug, Deserialize)]
+pub struct WebhookEventType {
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// JSON Schema for the event's `data`.
+    pub schema: serde_json::Value,
+}
+
+/// Returned by [`Client::public_stats`]; may be up to a minute or so out of date.
+#[derive(Debug, Deserialize)]
+pub struct PublicStats {
+    pub registered_users: i64,
+    pub as_of: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UsernameAvailability {
+    pub username: String,
+    pub available: bool,
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub suggestions: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserChange {
+    pub cursor: i64,
+    pub username: String,
+    pub change: String,
+    pub changed_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserChangesPage {
+    pub changes: Vec<UserChange>,
+    pub next_cursor: i64,
+    pub has_more: bool,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AuditQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// RFC 3339, inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// RFC 3339, exclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub principal: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditEvent {
+    pub id: i64,
+    /// A username, or `@admin` for the admin token and `@anonymous` for none.
+    pub actor: String,
+    pub action: String,
+    pub target: String,
+    pub request_id: Option<String>,
+    /// From the request's `traceparent` header, if it sent one.
+    pub trace_id: Option<String>,
+    pub client_ip: Option<String>,
+    /// Who the request authenticated as, which may differ from `actor`.
+    pub principal: Option<String>,
+    pub diff: serde_json::Value,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditPage {
+    pub items: Vec<AuditEvent>,
+    pub next_cursor: Option<i64>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct AuditSummaryQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    /// First day of a month, e.g. `2024-01-01`; inclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// First day of a month; exclusive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+/// Audit events of one month by one actor with one action, counted when the raw
+/// events were purged.
+#[derive(Debug, Deserialize)]
+pub struct AuditSummary {
+    pub month: String,
+    pub actor: String,
+    pub action: String,
+    pub events: i64,
+    pub first_at: String,
+    pub last_at: String,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoleChangeRequest {
+    pub role: Role,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoleChange {
+    pub id: i64,
+    pub username: String,
+    pub role: Role,
+    pub reason: Option<String>,
+    pub requested_by: String,
+    /// `pending`, `approved` or `rejected`.
+    pub status: String,
+    pub decided_by: Option<String>,
+    pub created_at: String,
+    pub decided_at: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateServiceAccount {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Defaults to `member`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<Role>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateFormat {
+    #[default]
+    Text,
+    /// Variables are HTML-escaped as they are substituted.
+    Html,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotificationTemplate {
+    pub name: String,
+    pub locale: String,
+    pub version: i32,
+    pub format: TemplateFormat,
+    pub subject: String,
+    pub body: String,
+    pub created_by: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateContent {
+    pub format: TemplateFormat,
+    pub subject: String,
+    pub body: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RenderedNotification {
+    pub name: String,
+    /// The locale whose template was used, which may be a fallback.
+    pub locale: String,
+    pub version: i32,
+    pub format: TemplateFormat,
+    pub subject: String,
+    pub body: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ServiceAccount {
+    pub name: String,
+    pub description: Option<String>,
+    pub role: Role,
+    pub key_prefix: String,
+    pub created_by: String,
+    pub created_at: String,
+    pub rotated_at: Option<String>,
+}
+
+/// A service account with its API key, which the server returns only once.
+#[derive(Debug, Deserialize)]
+pub struct ServiceAccountKey {
+    pub account: ServiceAccount,
+    pub key: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ServiceAccountCredentials {
+    pub key: String,
+}
+
+/// The JSON body of every error response.
+#[derive(Debug, Deserialize)]
+pub struct ErrorBody {
+    pub code: String,
+    pub message: String,
+    pub request_id: Option<String>,
+    pub details: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Display)]
+pub enum Error {
+    #[display(fmt = "{} {}: {}", status, "body.code", "body.message")]
+    Api { status: StatusCode, body: ErrorBody },
+    #[display(fmt = "unexpected {} response", _0)]
+    UnexpectedStatus(StatusCode),
+    #[display(fmt = "{}", _0)]
+    Http(reqwest::Error),
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
+
+impl Error {
+    /// The server's error code, such as `NOT_FOUND` or `STEP_UP_REQUIRED`.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Error::Api { body, .. } => Some(&body.code),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Client {
+    http: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Client {
+            http: reqwest::Client::new(),
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+            token: None,
+        }
+    }
+
+    /// Sends `token` as the bearer credential: a user token from [`Client::login`]
+    /// or the static admin token.
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let builder = self
+            .http
+            .request(method, format!("{}{}", self.base_url, path));
+        match &self.token {
+            Some(token) => builder.bearer_auth(token),
+            None => builder,
+        }
+    }
+
+    async fn check(response: Response) -> Result<Response, Error> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        match response.json::<ErrorBody>().await {
+            Ok(body) => Err(Error::Api { status, body }),
+            Err(_) => Err(Error::UnexpectedStatus(status)),
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(builder: RequestBuilder) -> Result<T, Error> {
+        Ok(Self::check(builder.send().await?).await?.json().await?)
+    }
+
+    async fn send_versioned<T: DeserializeOwned>(
+        builder: RequestBuilder,
+    ) -> Result<Versioned<T>, Error> {
+        let response = Self::check(builder.send().await?).await?;
+        let value = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_owned)
+        };
+        let etag = value(header::ETAG);
+        let last_modified = value(header::LAST_MODIFIED);
+        let session_token = value(header::HeaderName::from_static(SESSION_TOKEN_HEADER));
+        Ok(Versioned {
+            value: response.json().await?,
+            etag,
+            last_modified,
+            session_token,
+        })
+    }
+
+    /// Exchanges a username and password for a bearer token, to be passed to
+    /// [`Client::with_token`].
+    pub async fn login(&self, credentials: &Credentials) -> Result<TokenResponse, Error> {
+        Self::send(self.request(Method::POST, routes::LOGIN).json(credentials)).await
+    }
+
+    /// Re-enters the password for a fresh token after a `STEP_UP_REQUIRED` error.
+    pub async fn step_up(&self, pwd: &str) -> Result<TokenResponse, Error> {
+        let body = Reauthenticate {
+            pwd: pwd.to_owned(),
+        };
+        Self::send(self.request(Method::POST, routes::LOGIN_STEP_UP).json(&body)).await
+    }
+
+    /// Creates the first admin user. Send the server's `admin.bootstrap_token` with
+    /// [`Client::with_token`]; once it has been used this fails with
+    /// `BOOTSTRAP_SPENT`.
+    pub async fn bootstrap(&self, user: &CreateUser) -> Result<UserResponse, Error> {
+        Self::send(self.request(Method::POST, routes::BOOTSTRAP).json(user)).await
+    }
+
+    pub async fn create_user(&self, user: &CreateUser) -> Result<UserResponse, Error> {
+        Self::send(self.request(Method::POST, routes::USERS).json(user)).await
+    }
+
+    /// Starts a signup whose steps are kept by the server until it expires.
+    pub async fn start_onboarding(&self) -> Result<OnboardingFlow, Error> {
+        Self::send(self.request(Method::POST, routes::ONBOARDING)).await
+    }
+
+    pub async fn get_onboarding(&self, id: &str) -> Result<OnboardingFlow, Error> {
+        Self::send(self.request(Method::GET, &routes::onboarding(id))).await
+    }
+
+    pub async fn submit_onboarding_step(
+        &self,
+        id: &str,
+        step: &OnboardingStep,
+    ) -> Result<OnboardingFlow, Error> {
+        Self::send(self.request(Method::POST, &routes::onboarding_steps(id)).json(step)).await
+    }
+
+    /// Creates the user the flow's steps describe. The password is only sent here.
+    pub async fn complete_onboarding(&self, id: &str, pwd: &str) -> Result<UserResponse, Error> {
+        let body = CompleteOnboarding {
+            pwd: pwd.to_owned(),
+        };
+        Self::send(self.request(Method::POST, &routes::onboarding_complete(id)).json(&body))
+            .await
+    }
+
+    pub async fn get_user(&self, username: &str) -> Result<Versioned<UserSummary>, Error> {
+        Self::send_versioned(self.request(Method::GET, &routes::user(username))).await
+    }
+
+    /// With an `etag` from [`Client::get_user`], the update only applies if the user
+    /// has not changed since; otherwise the server answers `PRECONDITION_FAILED`.
+    pub async fn update_user(
+        &self,
+        username: &str,
+        update: &UserUpdate,
+        etag: Option<&str>,
+    ) -> Result<Versioned<UserSummary>, Error> {
+        let mut builder = self
+            .request(Method::PATCH, &routes::user(username))
+            .json(update);
+        if let Some(etag) = etag {
+            builder = builder.header(header::IF_MATCH, etag);
+        }
+        Self::send_versioned(builder).await
+    }
+
+    /// Schedules deletion of the caller's own account. Needs a stepped-up token.
+    pub async fn delete_me(&self) -> Result<ScheduledDeletion, Error> {
+        Self::send(self.request(Method::DELETE, routes::ME)).await
+    }
+
+    /// Which fields the caller's account is, or will be, required to fill in.
+    pub async fn profile_completeness(&self) -> Result<ProfileCompleteness, Error> {
+        Self::send(self.request(Method::GET, routes::ME_PROFILE_COMPLETENESS)).await
+    }
+
+    /// Admin only: brings back a soft-deleted user, taking a seat if a limit is set.
+    pub async fn restore_user(&self, username: &str) -> Result<UserSummary, Error> {
+        Self::send(self.request(Method::POST, &routes::user_restore(username))).await
+    }
+
+    pub async fn delete_user(&self, username: &str, etag: Option<&str>) -> Result<(), Error> {
+        let mut builder = self.request(Method::DELETE, &routes::user(username));
+        if let Some(etag) = etag {
+            builder = builder.header(header::IF_MATCH, etag);
+        }
+        Self::check(builder.send().await?).await.map(|_| ())
+    }
+
+    pub async fn list_users(&self, query: &UserListQuery) -> Result<UserPage, Error> {
+        let labels: Vec<(&str, &str)> = query
+            .labels
+            .iter()
+            .map(|label| ("label", label.as_str()))
+            .collect();
+        Self::send(
+            self.request(Method::GET, routes::USERS)
+                .query(query)
+                .query(&labels),
+        )
+        .await
+    }
+
+    /// Admin only: like [`Client::list_users`], but over the shared database and every
+    /// regional one.
+    pub async fn list_users_all_regions(&self, query: &UserListQuery) -> Result<UserPage, Error> {
+        let labels: Vec<(&str, &str)> = query
+            .labels
+            .iter()
+            .map(|label| ("label", label.as_str()))
+            .collect();
+        Self::send(
+            self.request(Method::GET, routes::ADMIN_REGIONS_USERS)
+                .query(query)
+                .query(&labels),
+        )
+        .await
+    }
+
+    /// Admin only: creates every valid user in `body` in one transaction. Rows
+    /// that fail are reported in the summary rather than as an error.
+    pub async fn import_users(
+        &self,
+        format: ImportFormat,
+        body: impl Into<reqwest::Body>,
+    ) -> Result<ImportSummary, Error> {
+        Self::send(
+            self.request(Method::POST, routes::USERS_IMPORT)
+                .header(header::CONTENT_TYPE, format.content_type())
+                .body(body),
+        )
+        .await
+    }
+
+    pub async fn lookup_users(&self, usernames: Vec<String>) -> Result<LookupResponse, Error> {
+        let body = LookupRequest { usernames };
+        Self::send(self.request(Method::POST, routes::USERS_LOOKUP).json(&body)).await
+    }
+
+    pub async fn search_users(
+        &self,
+        q: &str,
+        limit: Option<i64>,
+    ) -> Result<Vec<SearchHit>, Error> {
+        let mut builder = self.request(Method::GET, routes::USERS_SEARCH).query(&[("q", q)]);
+        if let Some(limit) = limit {
+            builder = builder.query(&[("limit", limit)]);
+        }
+        Self::send(builder).await
+    }
+
+    /// Changes after `since`. Without one, returns only the current cursor.
+    pub async fn user_changes(
+        &self,
+        since: Option<i64>,
+        limit: Option<i64>,
+    ) -> Result<UserChangesPage, Error> {
+        let mut builder = self.request(Method::GET, routes::USERS_CHANGES);
+        if let Some(since) = since {
+            builder = builder.query(&[("since", since)]);
+        }
+        if let Some(limit) = limit {
+            builder = builder.query(&[("limit", limit)]);
+        }
+        Self::send(builder).await
+    }
+
+    /// Admin only. Pass `next_cursor` back as `before` for the next page.
+    pub async fn list_audit(&self, query: &AuditQuery) -> Result<AuditPage, Error> {
+        Self::send(self.request(Method::GET, routes::AUDIT).query(query)).await
+    }
+
+    /// Admin only: monthly counts for audit events past the server's retention.
+    pub async fn list_audit_summaries(
+        &self,
+        query: &AuditSummaryQuery,
+    ) -> Result<Vec<AuditSummary>, Error> {
+        Self::send(self.request(Method::GET, routes::AUDIT_SUMMARIES).query(query)).await
+    }
+
+    /// Admin only: the event types `POST /webhooks/{source}` accepts.
+    pub async fn webhook_event_types(&self) -> Result<Vec<WebhookEventType>, Error> {
+        Self::send(self.request(Method::GET, routes::ADMIN_WEBHOOK_EVENTS)).await
+    }
+
+    pub async fn check_username(
+        &self,
+        username: &str,
+        suggest: bool,
+    ) -> Result<UsernameAvailability, Error> {
+        Self::send(
+            self.request(Method::GET, routes::USERNAMES_CHECK)
+                .query(&[("username", username)])
+                .query(&[("suggest", suggest)]),
+        )
+        .await
+    }
+
+    /// Needs no credentials.
+    pub async fn public_stats(&self) -> Result<PublicStats, Error> {
+        Self::send(self.request(Method::GET, routes::STATS_PUBLIC)).await
+    }
+
+    pub async fn add_labels(&self, username: &str, labels: Vec<String>) -> Result<Labels, Error> {
+        let body = Labels { labels };
+        Self::send(
+            self.request(Method::POST, &routes::user_labels(username))
+                .json(&body),
+        )
+        .await
+    }
+
+    pub async fn remove_label(&self, username: &str, label: &str) -> Result<Labels, Error> {
+        Self::send(self.request(Method::DELETE, &routes::user_label(username, label))).await
+    }
+
+    /// The user's storage against their quotas; users may read their own.
+    pub async fn user_quota(&self, username: &str) -> Result<UserQuotas, Error> {
+        Self::send(self.request(Method::GET, &routes::user_quota(username))).await
+    }
+
+    /// Admin only: asks for a role change, which applies once a different admin
+    /// approves it with [`Client::approve_role_change`].
+    pub async fn request_role_change(
+        &self,
+        username: &str,
+        request: &RoleChangeRequest,
+    ) -> Result<RoleChange, Error> {
+        Self::send(
+            self.request(Method::PATCH, &routes::user_role(username))
+                .json(request),
+        )
+        .await
+    }
+
+    /// Admin only: pending role changes, oldest first.
+    pub async fn list_role_changes(&self, limit: Option<i64>) -> Result<Vec<RoleChange>, Error> {
+        let mut builder = self.request(Method::GET, routes::ADMIN_ROLE_CHANGES);
+        if let Some(limit) = limit {
+            builder = builder.query(&[("limit", limit)]);
+        }
+        Self::send(builder).await
+    }
+
+    pub async fn approve_role_change(&self, id: i64) -> Result<RoleChange, Error> {
+        Self::send(self.request(Method::POST, &routes::role_change_approve(id))).await
+    }
+
+    pub async fn reject_role_change(&self, id: i64) -> Result<RoleChange, Error> {
+        Self::send(self.request(Method::POST, &routes::role_change_reject(id))).await
+    }
+
+    /// Exchanges a service account's API key for a bearer token, to be passed to
+    /// [`Client::with_token`].
+    pub async fn service_account_token(&self, key: &str) -> Result<TokenResponse, Error> {
+        let body = ServiceAccountCredentials {
+            key: key.to_owned(),
+        };
+        Self::send(
+            self.request(Method::POST, routes::SERVICE_ACCOUNTS_TOKEN)
+                .json(&body),
+        )
+        .await
+    }
+
+    /// Admin only, after a step-up: the returned key is not shown again.
+    pub async fn create_service_account(
+        &self,
+        account: &CreateServiceAccount,
+    ) -> Result<ServiceAccountKey, Error> {
+        Self::send(
+            self.request(Method::POST, routes::ADMIN_SERVICE_ACCOUNTS)
+                .json(account),
+        )
+        .await
+    }
+
+    pub async fn list_service_accounts(
+        &self,
+        limit: Option<i64>,
+    ) -> Result<Vec<ServiceAccount>, Error> {
+        let mut builder = self.request(Method::GET, routes::ADMIN_SERVICE_ACCOUNTS);
+        if let Some(limit) = limit {
+            builder = builder.query(&[("limit", limit)]);
+        }
+        Self::send(builder).await
+    }
+
+    /// Admin only: replaces the account's key; the old one stops working at once.
+    pub async fn rotate_service_account_key(&self, name: &str) -> Result<ServiceAccountKey, Error> {
+        Self::send(self.request(Method::POST, &routes::service_account_rotate(name))).await
+    }
+
+    pub async fn delete_service_account(&self, name: &str) -> Result<(), Error> {
+        let builder = self.request(Method::DELETE, &routes::service_account(name));
+        Self::check(builder.send().await?).await.map(|_| ())
+    }
+
+    /// Admin only: the newest version of every notification template.
+    pub async fn list_templates(&self) -> Result<Vec<NotificationTemplate>, Error> {
+        Self::send(self.request(Method::GET, routes::ADMIN_TEMPLATES)).await
+    }
+
+    /// Admin only: `version` of a template, or its newest.
+    pub async fn get_template(
+        &self,
+        name: &str,
+        locale: &str,
+        version: Option<i32>,
+    ) -> Result<NotificationTemplate, Error> {
+        let mut builder = self.request(Method::GET, &routes::template(name, locale));
+        if let Some(version) = version {
+            builder = builder.query(&[("version", version)]);
+        }
+        Self::send(builder).await
+    }
+
+    /// Admin only: every version of a template, newest first.
+    pub async fn template_versions(
+        &self,
+        name: &str,
+        locale: &str,
+    ) -> Result<Vec<NotificationTemplate>, Error> {
+        Self::send(self.request(Method::GET, &routes::template_versions(name, locale))).await
+    }
+
+    /// Admin only: saves `content` as the template's next version.
+    pub async fn put_template(
+        &self,
+        name: &str,
+        locale: &str,
+        content: &TemplateContent,
+    ) -> Result<NotificationTemplate, Error> {
+        Self::send(
+            self.request(Method::PUT, &routes::template(name, locale))
+                .json(content),
+        )
+        .await
+    }
+
+    /// Admin only: deletes every version of a template in one locale.
+    pub async fn delete_template(&self, name: &str, locale: &str) -> Result<(), Error> {
+        let builder = self.request(Method::DELETE, &routes::template(name, locale));
+        Self::check(builder.send().await?).await.map(|_| ())
+    }
+
+    /// Admin only: renders a template with `variables`, falling back from `locale`
+    /// to its language and then the server's default locale.
+    pub async fn render_template(
+        &self,
+        name: &str,
+        locale: Option<&str>,
+        variables: serde_json::Value,
+    ) -> Result<RenderedNotification, Error> {
+        let body = serde_json::json!({ "locale": locale, "variables": variables });
+        Self::send(
+            self.request(Method::POST, &routes::template_render(name))
+                .json(&body),
+        )
+        .await
+    }
+}
@@ -0,0 +1,11 @@
+UPDATE oleander.users
+SET labels = ARRAY(
+    SELECT l FROM unnest(labels) AS l WHERE split_part(l, '=', 1) <> ALL($3)
+    UNION
+    SELECT unnest($2::text[])
+    ORDER BY 1
+),
+version = version + 1
+WHERE lower(username) = $1 AND deleted_at IS NULL
+
+RETURNING labels;
\ No newline at end of file
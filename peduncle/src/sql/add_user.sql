@@ -1,4 +1,4 @@
-INSERT INTO oleander.users(username, first_name, last_name, pwd)
-VALUES ($1, $2, $3, $4)
+INSERT INTO oleander.users(username, first_name, last_name, pwd, username_skeleton, email)
+VALUES ($1, $2, $3, $4, $5, $6)
 
 RETURNING $table_fields;
\ No newline at end of file
@@ -0,0 +1,6 @@
+SELECT id, actor, action, target, request_id, diff, created_at, trace_id, client_ip, principal
+FROM oleander.audit_events
+WHERE id > $1
+    AND created_at < now() - ($3::int * interval '1 millisecond')
+ORDER BY id
+LIMIT $2;
\ No newline at end of file
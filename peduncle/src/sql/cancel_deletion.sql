@@ -0,0 +1,4 @@
+UPDATE oleander.users
+SET deletion_due_at = NULL
+WHERE lower(username) = lower($1) AND deleted_at IS NULL AND deletion_due_at IS NOT NULL
+RETURNING username
\ No newline at end of file
@@ -0,0 +1,10 @@
+UPDATE oleander.enrichment_jobs
+SET attempts = attempts + 1, run_after = now() + $2::int * interval '1 second'
+WHERE id IN (
+    SELECT id FROM oleander.enrichment_jobs
+    WHERE run_after <= now()
+    ORDER BY run_after
+    LIMIT $1
+    FOR UPDATE SKIP LOCKED
+)
+RETURNING id, username, trigger, attempts
\ No newline at end of file
@@ -0,0 +1,3 @@
+INSERT INTO oleander.request_nonces (nonce)
+VALUES ($1)
+ON CONFLICT (nonce) DO NOTHING;
\ No newline at end of file
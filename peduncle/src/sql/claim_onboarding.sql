@@ -0,0 +1,4 @@
+UPDATE oleander.onboarding_flows
+SET completed_at = now()
+WHERE id = $1 AND completed_at IS NULL AND expires_at > now()
+RETURNING steps
\ No newline at end of file
@@ -0,0 +1,3 @@
+INSERT INTO oleander.webhook_deliveries (source, delivery_id, event_type)
+VALUES ($1, $2, $3)
+ON CONFLICT (source, delivery_id) DO NOTHING;
\ No newline at end of file
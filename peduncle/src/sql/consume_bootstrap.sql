@@ -0,0 +1,6 @@
+INSERT INTO oleander.bootstrap (id, username)
+SELECT 1, $1
+WHERE NOT EXISTS (
+    SELECT 1 FROM oleander.users WHERE role = 'admin' AND deleted_at IS NULL
+)
+ON CONFLICT DO NOTHING;
\ No newline at end of file
@@ -0,0 +1,2 @@
+COPY import_users (line, username, first_name, last_name, pwd, username_skeleton, email)
+FROM STDIN (FORMAT binary);
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT coalesce(sum(value), 0)::bigint FROM oleander.counter_shards WHERE name = $1;
\ No newline at end of file
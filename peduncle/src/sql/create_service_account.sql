@@ -0,0 +1,4 @@
+INSERT INTO oleander.service_accounts (name, description, role, key_prefix, key_hash, created_by)
+VALUES ($1, $2, $3, $4, $5, $6)
+ON CONFLICT (name) DO NOTHING
+RETURNING name, description, role, key_prefix, created_by, created_at, rotated_at;
\ No newline at end of file
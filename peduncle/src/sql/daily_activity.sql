@@ -0,0 +1,4 @@
+SELECT day, signups, logins
+FROM oleander.analytics_daily
+WHERE day > current_date - $1::int
+ORDER BY day;
\ No newline at end of file
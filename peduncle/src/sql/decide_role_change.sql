@@ -0,0 +1,4 @@
+UPDATE oleander.role_changes
+SET status = $2, decided_by = $3, decided_at = now()
+WHERE id = $1 AND status = 'pending'
+RETURNING id, username, role, reason, requested_by, status, decided_by, created_at, decided_at;
\ No newline at end of file
@@ -1 +1,5 @@
-DELETE FROM oleander.users WHERE username LIKE $1;
\ No newline at end of file
+UPDATE oleander.users
+SET deleted_at = now(), version = version + 1
+WHERE lower(username) = lower($1)
+    AND deleted_at IS NULL
+    AND ($2::bigint IS NULL OR version = $2);
\ No newline at end of file
@@ -0,0 +1,2 @@
+DELETE FROM oleander.service_accounts
+WHERE name = $1;
\ No newline at end of file
@@ -0,0 +1,2 @@
+DELETE FROM oleander.notification_templates
+WHERE name = $1 AND locale = $2;
\ No newline at end of file
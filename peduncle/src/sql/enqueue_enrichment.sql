@@ -0,0 +1,2 @@
+INSERT INTO oleander.enrichment_jobs (username, trigger) VALUES ($1, $2)
+ON CONFLICT (username) DO NOTHING
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT id, username, first_name, last_name, labels, profile, created_at, deleted_at, pwd, email
+FROM oleander.users
+WHERE id > $1
+ORDER BY id
+LIMIT $2;
\ No newline at end of file
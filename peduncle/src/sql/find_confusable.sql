@@ -0,0 +1 @@
+SELECT username FROM oleander.users WHERE username_skeleton = $1 AND lower(username) <> lower($2) LIMIT 1;
\ No newline at end of file
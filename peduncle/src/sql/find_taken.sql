@@ -0,0 +1 @@
+SELECT lower(username) FROM oleander.users WHERE lower(username) = ANY($1);
\ No newline at end of file
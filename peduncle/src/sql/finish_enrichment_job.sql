@@ -0,0 +1 @@
+DELETE FROM oleander.enrichment_jobs WHERE id = $1
\ No newline at end of file
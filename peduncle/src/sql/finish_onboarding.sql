@@ -0,0 +1,3 @@
+UPDATE oleander.onboarding_flows
+SET username = $2, updated_at = now()
+WHERE id = $1
\ No newline at end of file
@@ -0,0 +1,9 @@
+SELECT username, first_name, last_name, labels, score
+FROM (
+    SELECT *, word_similarity($1, username || ' ' || first_name || ' ' || last_name) AS score
+    FROM oleander.users
+    WHERE deleted_at IS NULL
+) AS scored
+WHERE score >= $2
+ORDER BY score DESC, username
+LIMIT $3;
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT schema FROM oleander.attribute_schema WHERE id = 1;
\ No newline at end of file
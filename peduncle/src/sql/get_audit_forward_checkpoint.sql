@@ -0,0 +1 @@
+SELECT last_id FROM oleander.audit_forward_checkpoints WHERE destination = $1;
\ No newline at end of file
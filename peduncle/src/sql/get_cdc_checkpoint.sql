@@ -0,0 +1 @@
+SELECT last_txid::text::bigint, last_seq FROM oleander.cdc_checkpoints WHERE consumer = $1;
\ No newline at end of file
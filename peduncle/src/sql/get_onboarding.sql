@@ -0,0 +1,3 @@
+SELECT id, steps, expires_at
+FROM oleander.onboarding_flows
+WHERE id = $1 AND completed_at IS NULL AND expires_at > now()
\ No newline at end of file
@@ -0,0 +1,3 @@
+SELECT username, pwd, role
+FROM oleander.users
+WHERE lower(username) = $1 AND deleted_at IS NULL;
\ No newline at end of file
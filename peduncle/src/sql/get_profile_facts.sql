@@ -0,0 +1 @@
+SELECT email, profile, created_at FROM oleander.users WHERE lower(username) = $1 AND deleted_at IS NULL;
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT name, locale, version, format, subject, body, created_by, created_at
+FROM oleander.notification_templates
+WHERE name = $1 AND locale = $2 AND ($3::int IS NULL OR version = $3)
+ORDER BY version DESC
+LIMIT 1;
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT username, first_name, last_name, labels, version, updated_at, deleted_at
+FROM oleander.users
+WHERE lower(username) = $1
+    AND ($3::boolean OR deleted_at IS NULL)
+    AND ($2::text IS NULL OR lower(username) = $2);
\ No newline at end of file
@@ -0,0 +1,3 @@
+SELECT username, first_name, last_name, labels
+FROM oleander.users
+WHERE lower(username) = ANY($1) AND deleted_at IS NULL;
\ No newline at end of file
@@ -0,0 +1,6 @@
+INSERT INTO oleander.users (
+    username, first_name, last_name, pwd, username_skeleton,
+    labels, profile, created_at, deleted_at, email
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+ON CONFLICT DO NOTHING;
\ No newline at end of file
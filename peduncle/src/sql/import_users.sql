@@ -0,0 +1,47 @@
+WITH candidates AS (
+    SELECT DISTINCT ON (s.username)
+        s.*,
+        $1::boolean AND EXISTS (
+            SELECT 1 FROM oleander.users u
+            WHERE u.username_skeleton = s.username_skeleton AND lower(u.username) <> s.username
+        ) AS confusable
+    FROM import_users s
+    ORDER BY s.username, s.line
+),
+inserted AS (
+    INSERT INTO oleander.users (username, first_name, last_name, pwd, username_skeleton, email)
+    SELECT username, first_name, last_name, pwd, username_skeleton, email
+    FROM candidates
+    WHERE NOT confusable
+    ORDER BY line
+    ON CONFLICT DO NOTHING
+    RETURNING id, username
+),
+activity AS (
+    INSERT INTO oleander.user_activity (user_id, kind)
+    SELECT id, $2 FROM inserted
+),
+audit AS (
+    INSERT INTO oleander.audit_events (
+        actor, action, target, request_id, diff, trace_id, client_ip, principal
+    )
+    SELECT $3, $5, c.username, $4, jsonb_build_object(
+        'first_name', c.first_name,
+        'last_name', c.last_name,
+        'email', c.email
+    ), $6, $7, $8
+    FROM inserted i
+    JOIN candidates c ON c.username = i.username
+)
+SELECT
+    s.line,
+    s.username,
+    CASE
+        WHEN c.line IS NULL THEN 'duplicate'
+        WHEN c.confusable THEN 'confusable'
+        WHEN i.id IS NULL THEN 'taken'
+    END AS rejected
+FROM import_users s
+LEFT JOIN candidates c ON c.line = s.line
+LEFT JOIN inserted i ON i.username = c.username
+ORDER BY s.line;
\ No newline at end of file
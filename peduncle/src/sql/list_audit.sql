@@ -0,0 +1,13 @@
+SELECT id, actor, action, target, request_id, diff, created_at, trace_id, client_ip, principal
+FROM oleander.audit_events
+WHERE ($1::text IS NULL OR actor = $1)
+    AND ($2::text IS NULL OR target = $2)
+    AND ($3::timestamptz IS NULL OR created_at >= $3)
+    AND ($4::timestamptz IS NULL OR created_at < $4)
+    AND ($5::bigint IS NULL OR id < $5)
+    AND ($7::text IS NULL OR request_id = $7)
+    AND ($8::text IS NULL OR trace_id = $8)
+    AND ($9::text IS NULL OR client_ip = $9)
+    AND ($10::text IS NULL OR principal = $10)
+ORDER BY id DESC
+LIMIT $6;
\ No newline at end of file
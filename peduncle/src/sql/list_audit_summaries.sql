@@ -0,0 +1,8 @@
+SELECT month, actor, action, events, first_at, last_at
+FROM oleander.audit_summaries
+WHERE ($1::text IS NULL OR actor = $1)
+    AND ($2::text IS NULL OR action = $2)
+    AND ($3::date IS NULL OR month >= $3)
+    AND ($4::date IS NULL OR month < $4)
+ORDER BY month DESC, actor, action
+LIMIT $5;
\ No newline at end of file
@@ -0,0 +1,4 @@
+SELECT name, description, role, key_prefix, created_by, created_at, rotated_at
+FROM oleander.service_accounts
+ORDER BY name
+LIMIT $1;
\ No newline at end of file
@@ -0,0 +1,4 @@
+SELECT DISTINCT ON (name, locale)
+    name, locale, version, format, subject, body, created_by, created_at
+FROM oleander.notification_templates
+ORDER BY name, locale, version DESC;
\ No newline at end of file
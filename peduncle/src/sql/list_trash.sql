@@ -0,0 +1,5 @@
+SELECT username, first_name, last_name, labels, deleted_at
+FROM oleander.users
+WHERE deleted_at IS NOT NULL
+ORDER BY deleted_at DESC
+LIMIT $1;
\ No newline at end of file
@@ -0,0 +1,18 @@
+WITH matched AS (
+    SELECT username, first_name, last_name, labels, deleted_at
+    FROM oleander.users
+    WHERE labels @> $1
+      AND ({live})
+      AND ($2::text IS NULL
+           OR username ILIKE $2
+           OR first_name ILIKE $2
+           OR last_name ILIKE $2)
+      AND ({filter})
+)
+SELECT total.n, page.username, page.first_name, page.last_name, page.labels, page.deleted_at
+FROM (SELECT count(*) AS n FROM matched) total
+LEFT JOIN LATERAL (
+    SELECT * FROM matched
+    ORDER BY {order}
+    LIMIT $3 OFFSET $4
+) page ON TRUE;
\ No newline at end of file
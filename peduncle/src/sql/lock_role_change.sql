@@ -0,0 +1,4 @@
+SELECT id, username, role, reason, requested_by, status, decided_by, created_at, decided_at
+FROM oleander.role_changes
+WHERE id = $1
+FOR UPDATE;
\ No newline at end of file
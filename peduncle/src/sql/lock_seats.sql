@@ -0,0 +1 @@
+SELECT pg_advisory_xact_lock(hashtext('oleander.seats'));
\ No newline at end of file
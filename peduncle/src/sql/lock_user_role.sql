@@ -0,0 +1,4 @@
+SELECT role
+FROM oleander.users
+WHERE lower(username) = $1 AND deleted_at IS NULL
+FOR UPDATE;
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Users homed in a regional database. Routing looks usernames up in the
+-- shared database's copy of user_regions, which also keeps usernames unique
+-- across regions; users.region records the home on the user's own row.
+ALTER TABLE oleander.users ADD COLUMN region VARCHAR(64);
+
+CREATE TABLE oleander.user_regions (
+    username    VARCHAR(200) PRIMARY KEY,
+    region      VARCHAR(64) NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
\ No newline at end of file
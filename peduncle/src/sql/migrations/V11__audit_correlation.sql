@@ -0,0 +1,13 @@
+-- Ties each event to the request behind it. request_id predates this migration.
+ALTER TABLE oleander.audit_events
+    ADD COLUMN trace_id VARCHAR(32),
+    ADD COLUMN client_ip VARCHAR(64),
+    ADD COLUMN principal VARCHAR(200);
+
+CREATE INDEX audit_events_request_id_idx ON oleander.audit_events (request_id);
+
+CREATE INDEX audit_events_trace_id_idx ON oleander.audit_events (trace_id);
+
+CREATE INDEX audit_events_client_ip_idx ON oleander.audit_events (client_ip, id DESC);
+
+CREATE INDEX audit_events_principal_idx ON oleander.audit_events (principal, id DESC);
\ No newline at end of file
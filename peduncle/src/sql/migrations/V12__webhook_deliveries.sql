@@ -0,0 +1,11 @@
+-- Inbound webhook deliveries already processed, so a retried delivery is
+-- acknowledged without being applied twice. Pruned after webhooks.retention_hours.
+CREATE TABLE oleander.webhook_deliveries (
+    source       VARCHAR(64) NOT NULL,
+    delivery_id  VARCHAR(200) NOT NULL,
+    event_type   VARCHAR(64) NOT NULL,
+    received_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (source, delivery_id)
+);
+
+CREATE INDEX webhook_deliveries_received_at_idx ON oleander.webhook_deliveries (received_at);
\ No newline at end of file
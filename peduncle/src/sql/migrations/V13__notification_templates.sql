@@ -0,0 +1,13 @@
+-- Every saved version of each notification template is kept; the newest is
+-- the one rendered. Locales are stored normalized, e.g. pt-br.
+CREATE TABLE oleander.notification_templates (
+    name        VARCHAR(64) NOT NULL,
+    locale      VARCHAR(64) NOT NULL,
+    version     INT NOT NULL,
+    format      VARCHAR(8) NOT NULL DEFAULT 'text',
+    subject     TEXT NOT NULL DEFAULT '',
+    body        TEXT NOT NULL,
+    created_by  VARCHAR(200) NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (name, locale, version)
+);
\ No newline at end of file
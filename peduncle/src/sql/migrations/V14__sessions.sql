@@ -0,0 +1,13 @@
+-- Server-side sessions named by user tokens (the `sid` claim). A token whose
+-- session is revoked or missing is rejected even if it has not expired. Rows
+-- are pruned once the tokens that could name them have expired.
+CREATE TABLE oleander.sessions (
+    id          VARCHAR(36) PRIMARY KEY,
+    username    VARCHAR(200) NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at  TIMESTAMPTZ NOT NULL,
+    revoked_at  TIMESTAMPTZ
+);
+
+CREATE INDEX sessions_username_idx ON oleander.sessions (lower(username));
+CREATE INDEX sessions_expires_at_idx ON oleander.sessions (expires_at);
\ No newline at end of file
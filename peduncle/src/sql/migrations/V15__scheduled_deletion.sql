@@ -0,0 +1,6 @@
+-- When an account deleted by its own user with DELETE /me is due to go. Until
+-- then it can be cancelled; afterwards the purge job deletes the row.
+ALTER TABLE oleander.users ADD COLUMN deletion_due_at TIMESTAMPTZ;
+
+CREATE INDEX users_deletion_due_at_idx ON oleander.users (deletion_due_at)
+    WHERE deletion_due_at IS NOT NULL;
\ No newline at end of file
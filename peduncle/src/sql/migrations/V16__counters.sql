@@ -0,0 +1,42 @@
+-- Figures shown to the public, kept as sharded counters so they can be read without a
+-- count(*) over users. Each change lands on one of 16 rows picked at random, so concurrent
+-- signups rarely wait on the same row lock; readers sum the shards.
+CREATE TABLE oleander.counter_shards (
+    name   VARCHAR(64) NOT NULL,
+    shard  SMALLINT    NOT NULL,
+    value  BIGINT      NOT NULL DEFAULT 0,
+    PRIMARY KEY (name, shard)
+);
+
+CREATE FUNCTION oleander.bump_counter(counter TEXT, delta BIGINT) RETURNS void AS $$
+BEGIN
+    INSERT INTO oleander.counter_shards (name, shard, value)
+    VALUES (counter, floor(random() * 16)::smallint, delta)
+    ON CONFLICT (name, shard)
+        DO UPDATE SET value = oleander.counter_shards.value + EXCLUDED.value;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Registered users are those not in the trash: soft deletes and restores count too.
+CREATE FUNCTION oleander.count_registered_users() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'INSERT' AND NEW.deleted_at IS NULL THEN
+        PERFORM oleander.bump_counter('registered_users', 1);
+    ELSIF TG_OP = 'DELETE' AND OLD.deleted_at IS NULL THEN
+        PERFORM oleander.bump_counter('registered_users', -1);
+    ELSIF TG_OP = 'UPDATE' AND (OLD.deleted_at IS NULL) <> (NEW.deleted_at IS NULL) THEN
+        PERFORM oleander.bump_counter(
+            'registered_users',
+            CASE WHEN NEW.deleted_at IS NULL THEN 1 ELSE -1 END
+        );
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER users_count_registered
+    AFTER INSERT OR UPDATE OF deleted_at OR DELETE ON oleander.users
+    FOR EACH ROW EXECUTE FUNCTION oleander.count_registered_users();
+
+INSERT INTO oleander.counter_shards (name, shard, value)
+SELECT 'registered_users', 0, count(*) FROM oleander.users WHERE deleted_at IS NULL;
\ No newline at end of file
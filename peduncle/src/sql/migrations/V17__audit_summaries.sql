@@ -0,0 +1,14 @@
+-- Monthly counts of audit events per actor and action. Events are added here in the
+-- same statement that purges them under audit.retention_days, and summaries are never
+-- purged, so long-term reporting outlives the raw rows.
+CREATE TABLE oleander.audit_summaries (
+    month     DATE         NOT NULL,
+    actor     VARCHAR(200) NOT NULL,
+    action    VARCHAR(64)  NOT NULL,
+    events    BIGINT       NOT NULL,
+    first_at  TIMESTAMPTZ  NOT NULL,
+    last_at   TIMESTAMPTZ  NOT NULL,
+    PRIMARY KEY (month, actor, action)
+);
+
+CREATE INDEX audit_summaries_actor_idx ON oleander.audit_summaries (actor, month DESC);
\ No newline at end of file
@@ -0,0 +1,15 @@
+-- Signups in progress. Each accepted step is kept under its name in `steps`, so a
+-- client can resume the flow from any device by its id. Passwords are never stored
+-- here: they are only sent with the request that completes the flow. Rows are pruned
+-- once expired; completing a flow records the username it created.
+CREATE TABLE oleander.onboarding_flows (
+    id            VARCHAR(36)  PRIMARY KEY,
+    steps         JSONB        NOT NULL DEFAULT '{}',
+    created_at    TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    updated_at    TIMESTAMPTZ  NOT NULL DEFAULT now(),
+    expires_at    TIMESTAMPTZ  NOT NULL,
+    completed_at  TIMESTAMPTZ,
+    username      VARCHAR(200)
+);
+
+CREATE INDEX onboarding_flows_expires_at_idx ON oleander.onboarding_flows (expires_at);
\ No newline at end of file
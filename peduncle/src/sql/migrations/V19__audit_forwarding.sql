@@ -0,0 +1,8 @@
+-- How far each audit.forward destination has read audit_events. Events after
+-- last_id are sent on the next poll, so a destination that was down catches up
+-- from where it stopped.
+CREATE TABLE oleander.audit_forward_checkpoints (
+    destination  VARCHAR(200) PRIMARY KEY,
+    last_id      BIGINT       NOT NULL,
+    updated_at   TIMESTAMPTZ  NOT NULL DEFAULT now()
+);
\ No newline at end of file
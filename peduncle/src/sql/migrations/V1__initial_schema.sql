@@ -0,0 +1,104 @@
+CREATE SCHEMA IF NOT EXISTS oleander;
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE TABLE oleander.users (
+    id          BIGSERIAL PRIMARY KEY,
+    first_name  VARCHAR(200) NOT NULL,
+    last_name   VARCHAR(200) NOT NULL,
+    username    VARCHAR(200) NOT NULL,
+    pwd         VARCHAR(200) NOT NULL,
+    email       VARCHAR(320),
+    username_skeleton VARCHAR(200) NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    last_active_at TIMESTAMPTZ,
+    labels      TEXT[] NOT NULL DEFAULT '{}',
+    profile     JSONB NOT NULL DEFAULT '{}',
+    version     BIGINT NOT NULL DEFAULT 1,
+    deleted_at  TIMESTAMPTZ,
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', username), 'A') ||
+        setweight(to_tsvector('simple', first_name || ' ' || last_name), 'B') ||
+        setweight(jsonb_to_tsvector('simple', profile, '["string"]'), 'C')
+    ) STORED
+);
+
+CREATE UNIQUE INDEX users_username_lower_idx ON oleander.users (lower(username));
+
+CREATE INDEX users_username_skeleton_idx ON oleander.users (username_skeleton);
+
+CREATE INDEX users_created_at_idx ON oleander.users (created_at);
+
+CREATE INDEX users_deleted_at_idx ON oleander.users (deleted_at) WHERE deleted_at IS NOT NULL;
+
+CREATE INDEX users_labels_idx ON oleander.users USING GIN (labels);
+
+CREATE INDEX users_search_vector_idx ON oleander.users USING GIN (search_vector);
+
+CREATE INDEX users_name_trgm_idx ON oleander.users
+    USING GIN ((username || ' ' || first_name || ' ' || last_name) gin_trgm_ops);
+
+CREATE TABLE oleander.analytics_daily (
+    day         DATE PRIMARY KEY,
+    signups     BIGINT NOT NULL DEFAULT 0,
+    logins      BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE TABLE oleander.user_activity (
+    id          BIGSERIAL PRIMARY KEY,
+    user_id     BIGINT NOT NULL REFERENCES oleander.users (id) ON DELETE CASCADE,
+    kind        VARCHAR(64) NOT NULL,
+    detail      JSONB NOT NULL DEFAULT '{}',
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX user_activity_user_id_idx ON oleander.user_activity (user_id, id DESC);
+
+CREATE TABLE oleander.attribute_schema (
+    id          SMALLINT PRIMARY KEY CHECK (id = 1),
+    schema      JSONB NOT NULL,
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE oleander.user_changes (
+    seq         BIGSERIAL PRIMARY KEY,
+    lsn         PG_LSN NOT NULL DEFAULT pg_current_wal_lsn(),
+    op          VARCHAR(16) NOT NULL,
+    username    VARCHAR(200) NOT NULL,
+    data        JSONB NOT NULL,
+    changed_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX user_changes_changed_at_idx ON oleander.user_changes (changed_at);
+
+CREATE TABLE oleander.cdc_checkpoints (
+    consumer    VARCHAR(200) PRIMARY KEY,
+    last_seq    BIGINT NOT NULL,
+    last_lsn    PG_LSN NOT NULL,
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE FUNCTION oleander.record_user_change() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        INSERT INTO oleander.user_changes (op, username, data)
+        VALUES ('delete', OLD.username, to_jsonb(OLD) - 'pwd' - 'search_vector');
+        RETURN OLD;
+    END IF;
+
+    INSERT INTO oleander.user_changes (op, username, data)
+    VALUES (lower(TG_OP), NEW.username, to_jsonb(NEW) - 'pwd' - 'search_vector');
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER users_record_change
+    AFTER INSERT OR UPDATE OR DELETE ON oleander.users
+    FOR EACH ROW EXECUTE FUNCTION oleander.record_user_change();
+
+CREATE TABLE oleander.request_nonces (
+    nonce       VARCHAR(200) PRIMARY KEY,
+    seen_at     TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX request_nonces_seen_at_idx ON oleander.request_nonces (seen_at);
\ No newline at end of file
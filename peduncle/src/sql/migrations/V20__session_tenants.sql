@@ -0,0 +1,4 @@
+-- The dedicated tenant a session's user lives in, from the token's `tenant`
+-- claim, or NULL for the shared database. The same username in two tenants is
+-- two different users, so ending one's sessions must leave the other's alone.
+ALTER TABLE oleander.sessions ADD COLUMN tenant VARCHAR(200);
@@ -0,0 +1,15 @@
+-- CDC consumers read user_changes in (txid, seq) order, and only changes whose
+-- transaction ended before every one still running began. A seq is taken at
+-- insert, not at commit, so checkpointing by seq alone skipped changes that
+-- committed after a later seq had been read.
+ALTER TABLE oleander.user_changes
+    ADD COLUMN txid XID8 NOT NULL DEFAULT pg_current_xact_id();
+
+CREATE INDEX user_changes_txid_seq_idx ON oleander.user_changes (txid, seq);
+
+-- Rows already recorded take this migration's txid above, and so does every
+-- checkpoint here, which therefore keeps its place among them by seq.
+ALTER TABLE oleander.cdc_checkpoints
+    ADD COLUMN last_txid XID8 NOT NULL DEFAULT pg_current_xact_id();
+
+ALTER TABLE oleander.cdc_checkpoints ALTER COLUMN last_txid DROP DEFAULT;
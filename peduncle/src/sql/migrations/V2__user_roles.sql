@@ -0,0 +1,3 @@
+ALTER TABLE oleander.users
+    ADD COLUMN role VARCHAR(20) NOT NULL DEFAULT 'member'
+    CHECK (role IN ('admin', 'member'));
\ No newline at end of file
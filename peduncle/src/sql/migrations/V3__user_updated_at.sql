@@ -0,0 +1,19 @@
+ALTER TABLE oleander.users
+    ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
+
+CREATE INDEX users_updated_at_idx ON oleander.users (updated_at);
+
+-- Follows `version`, so housekeeping writes such as password rehashes do not
+-- invalidate clients' Last-Modified caches.
+CREATE FUNCTION oleander.touch_updated_at() RETURNS trigger AS $$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER users_touch_updated_at
+    BEFORE UPDATE ON oleander.users
+    FOR EACH ROW
+    WHEN (OLD.version IS DISTINCT FROM NEW.version)
+    EXECUTE FUNCTION oleander.touch_updated_at();
\ No newline at end of file
@@ -0,0 +1,16 @@
+CREATE TABLE oleander.audit_events (
+    id          BIGSERIAL PRIMARY KEY,
+    actor       VARCHAR(200) NOT NULL,
+    action      VARCHAR(64) NOT NULL,
+    target      VARCHAR(200) NOT NULL,
+    request_id  VARCHAR(200),
+    diff        JSONB NOT NULL DEFAULT '{}',
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- No foreign key to users: events must outlive the accounts they describe.
+CREATE INDEX audit_events_created_at_idx ON oleander.audit_events (created_at);
+
+CREATE INDEX audit_events_actor_idx ON oleander.audit_events (actor, id DESC);
+
+CREATE INDEX audit_events_target_idx ON oleander.audit_events (target, id DESC);
\ No newline at end of file
@@ -0,0 +1,6 @@
+-- Written when admin.bootstrap_token is first used; from then on the token is refused.
+CREATE TABLE oleander.bootstrap (
+    id          SMALLINT PRIMARY KEY CHECK (id = 1),
+    username    VARCHAR(200) NOT NULL,
+    used_at     TIMESTAMPTZ NOT NULL DEFAULT now()
+);
\ No newline at end of file
@@ -0,0 +1,18 @@
+-- A role change asked for by one admin, applied only once a different admin
+-- approves it.
+CREATE TABLE oleander.role_changes (
+    id            BIGSERIAL PRIMARY KEY,
+    username      VARCHAR(200) NOT NULL,
+    role          VARCHAR(20) NOT NULL CHECK (role IN ('admin', 'member')),
+    reason        TEXT,
+    requested_by  VARCHAR(200) NOT NULL,
+    status        VARCHAR(20) NOT NULL DEFAULT 'pending'
+                  CHECK (status IN ('pending', 'approved', 'rejected')),
+    decided_by    VARCHAR(200),
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now(),
+    decided_at    TIMESTAMPTZ
+);
+
+-- At most one open request per user, so two approvals cannot apply conflicting roles.
+CREATE UNIQUE INDEX role_changes_pending_idx ON oleander.role_changes (username)
+    WHERE status = 'pending';
\ No newline at end of file
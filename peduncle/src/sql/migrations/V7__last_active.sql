@@ -0,0 +1,14 @@
+-- last_active_at is flushed in batches for every active user. Recording those
+-- writes as user changes would flood user_changes and every CDC consumer, so
+-- updates touching only that column are left out.
+DROP TRIGGER users_record_change ON oleander.users;
+
+CREATE TRIGGER users_record_change
+    AFTER INSERT OR DELETE ON oleander.users
+    FOR EACH ROW EXECUTE FUNCTION oleander.record_user_change();
+
+CREATE TRIGGER users_record_update
+    AFTER UPDATE ON oleander.users
+    FOR EACH ROW
+    WHEN (OLD.last_active_at IS NOT DISTINCT FROM NEW.last_active_at)
+    EXECUTE FUNCTION oleander.record_user_change();
\ No newline at end of file
@@ -0,0 +1,14 @@
+-- Machine identities, kept out of oleander.users so listings, reports and
+-- seat counts only see people. They have no password or profile; a service
+-- account signs in by exchanging its API key, stored here only as a SHA-256.
+CREATE TABLE oleander.service_accounts (
+    id           BIGSERIAL PRIMARY KEY,
+    name         VARCHAR(64) NOT NULL UNIQUE,
+    description  VARCHAR(200),
+    role         VARCHAR(20) NOT NULL CHECK (role IN ('admin', 'member')),
+    key_prefix   VARCHAR(16) NOT NULL,
+    key_hash     CHAR(64) NOT NULL UNIQUE,
+    created_by   VARCHAR(200) NOT NULL,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    rotated_at   TIMESTAMPTZ
+);
\ No newline at end of file
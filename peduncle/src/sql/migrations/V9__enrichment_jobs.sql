@@ -0,0 +1,14 @@
+-- Profile lookups waiting for an enrichment hook. At most one per user is
+-- queued; attempts counts claims, and run_after doubles as the claim lease
+-- and the retry backoff.
+CREATE TABLE oleander.enrichment_jobs (
+    id          BIGSERIAL PRIMARY KEY,
+    username    VARCHAR(200) NOT NULL UNIQUE,
+    trigger     VARCHAR(20) NOT NULL CHECK (trigger IN ('created', 'login')),
+    attempts    INT NOT NULL DEFAULT 0,
+    last_error  TEXT,
+    run_after   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX enrichment_jobs_run_after ON oleander.enrichment_jobs (run_after);
\ No newline at end of file
@@ -0,0 +1,3 @@
+SELECT t
+FROM unnest($1::text[]) AS t
+WHERE to_regclass('oleander.' || t) IS NULL;
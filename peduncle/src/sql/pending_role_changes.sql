@@ -0,0 +1,5 @@
+SELECT id, username, role, reason, requested_by, status, decided_by, created_at, decided_at
+FROM oleander.role_changes
+WHERE status = 'pending'
+ORDER BY id
+LIMIT $1;
\ No newline at end of file
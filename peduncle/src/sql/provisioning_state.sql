@@ -0,0 +1,3 @@
+SELECT username, first_name, last_name, role, labels, deleted_at IS NOT NULL
+FROM oleander.users
+ORDER BY username
\ No newline at end of file
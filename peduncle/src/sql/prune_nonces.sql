@@ -0,0 +1,2 @@
+DELETE FROM oleander.request_nonces
+WHERE seen_at < now() - ($1::int * interval '1 second');
\ No newline at end of file
@@ -0,0 +1,2 @@
+DELETE FROM oleander.onboarding_flows
+WHERE expires_at < now()
\ No newline at end of file
@@ -0,0 +1,2 @@
+DELETE FROM oleander.user_changes
+WHERE changed_at < now() - ($1::int * interval '1 hour');
\ No newline at end of file
@@ -0,0 +1,2 @@
+DELETE FROM oleander.webhook_deliveries
+WHERE received_at < now() - ($1::int * interval '1 hour');
\ No newline at end of file
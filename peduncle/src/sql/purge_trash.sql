@@ -0,0 +1,2 @@
+DELETE FROM oleander.users
+WHERE deleted_at < now() - ($1::int * interval '1 day');
\ No newline at end of file
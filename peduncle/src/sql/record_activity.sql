@@ -0,0 +1,2 @@
+INSERT INTO oleander.user_activity(user_id, kind, detail)
+SELECT id, $2, $3 FROM oleander.users WHERE lower(username) = $1;
\ No newline at end of file
@@ -0,0 +1,4 @@
+INSERT INTO oleander.audit_events (
+    actor, action, target, request_id, diff, trace_id, client_ip, principal
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8);
\ No newline at end of file
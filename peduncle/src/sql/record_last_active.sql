@@ -0,0 +1,4 @@
+UPDATE oleander.users AS u
+SET last_active_at = greatest(u.last_active_at, seen.at)
+FROM unnest($1::varchar[], $2::timestamptz[]) AS seen (username, at)
+WHERE lower(u.username) = seen.username
\ No newline at end of file
@@ -0,0 +1,3 @@
+INSERT INTO oleander.user_regions (username, region)
+VALUES ($1, $2)
+ON CONFLICT DO NOTHING
\ No newline at end of file
@@ -0,0 +1,3 @@
+UPDATE oleander.users
+SET pwd = $3
+WHERE lower(username) = lower($1) AND pwd = $2 AND deleted_at IS NULL
\ No newline at end of file
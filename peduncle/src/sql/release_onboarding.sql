@@ -0,0 +1,3 @@
+UPDATE oleander.onboarding_flows
+SET completed_at = NULL
+WHERE id = $1 AND username IS NULL
\ No newline at end of file
@@ -0,0 +1,5 @@
+UPDATE oleander.users
+SET labels = array_remove(labels, $2), version = version + 1
+WHERE lower(username) = $1 AND deleted_at IS NULL
+
+RETURNING labels;
\ No newline at end of file
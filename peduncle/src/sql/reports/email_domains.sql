@@ -0,0 +1,5 @@
+SELECT lower(split_part(email, '@', 2)) AS domain, count(*) AS users
+FROM oleander.users
+WHERE email IS NOT NULL AND deleted_at IS NULL
+GROUP BY domain
+ORDER BY users DESC, domain
\ No newline at end of file
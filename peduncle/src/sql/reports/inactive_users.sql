@@ -0,0 +1,5 @@
+SELECT username, first_name, last_name, email, last_active_at
+FROM oleander.users
+WHERE deleted_at IS NULL
+    AND coalesce(last_active_at, created_at) < now() - make_interval(days => $1::int)
+ORDER BY last_active_at NULLS FIRST, username
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT created_at::date AS day, count(*) AS signups
+FROM oleander.users
+WHERE created_at >= $1::date AND created_at < $2::date + 1
+GROUP BY day
+ORDER BY day
\ No newline at end of file
@@ -0,0 +1,4 @@
+SELECT username, first_name, last_name, labels, created_at
+FROM oleander.users
+WHERE labels @> ARRAY[$1::text] AND deleted_at IS NULL
+ORDER BY username
\ No newline at end of file
@@ -0,0 +1,4 @@
+INSERT INTO oleander.role_changes (username, role, reason, requested_by)
+VALUES ($1, $2, $3, $4)
+ON CONFLICT (username) WHERE status = 'pending' DO NOTHING
+RETURNING id, username, role, reason, requested_by, status, decided_by, created_at, decided_at;
\ No newline at end of file
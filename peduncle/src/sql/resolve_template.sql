@@ -0,0 +1,5 @@
+SELECT name, locale, version, format, subject, body, created_by, created_at
+FROM oleander.notification_templates
+WHERE name = $1 AND locale = ANY($2::text[])
+ORDER BY array_position($2::text[], locale::text), version DESC
+LIMIT 1;
\ No newline at end of file
@@ -0,0 +1,5 @@
+UPDATE oleander.users
+SET deleted_at = NULL, version = version + 1
+WHERE lower(username) = $1 AND deleted_at IS NOT NULL
+
+RETURNING username, first_name, last_name, labels;
\ No newline at end of file
@@ -0,0 +1,3 @@
+UPDATE oleander.enrichment_jobs
+SET last_error = $2, run_after = now() + $3::int * interval '1 second'
+WHERE id = $1
\ No newline at end of file
@@ -0,0 +1,3 @@
+UPDATE oleander.sessions
+SET revoked_at = now()
+WHERE id = $1 AND revoked_at IS NULL
\ No newline at end of file
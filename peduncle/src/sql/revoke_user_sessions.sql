@@ -0,0 +1,8 @@
+UPDATE oleander.sessions
+SET revoked_at = now()
+WHERE tenant IS NOT DISTINCT FROM $1
+  AND lower(username) = lower($2)
+  AND id IS DISTINCT FROM $3
+  AND revoked_at IS NULL
+  AND expires_at > now()
+RETURNING id
\ No newline at end of file
@@ -0,0 +1,10 @@
+INSERT INTO oleander.analytics_daily (day, signups, logins)
+SELECT
+    day::date,
+    (SELECT count(*) FROM oleander.users WHERE created_at::date = day::date),
+    (SELECT count(*) FROM oleander.users WHERE last_active_at::date = day::date)
+FROM generate_series(current_date - 1, current_date, interval '1 day') AS day
+
+ON CONFLICT (day) DO UPDATE SET signups = EXCLUDED.signups, logins = EXCLUDED.logins
+
+RETURNING day, signups, logins;
\ No newline at end of file
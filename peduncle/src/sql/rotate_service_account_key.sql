@@ -0,0 +1,4 @@
+UPDATE oleander.service_accounts
+SET key_prefix = $2, key_hash = $3, rotated_at = now()
+WHERE name = $1
+RETURNING name, description, role, key_prefix, created_by, created_at, rotated_at;
\ No newline at end of file
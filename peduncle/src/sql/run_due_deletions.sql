@@ -0,0 +1,3 @@
+DELETE FROM oleander.users
+WHERE deletion_due_at <= now()
+RETURNING username
\ No newline at end of file
@@ -0,0 +1,8 @@
+INSERT INTO oleander.notification_templates (
+    name, locale, version, format, subject, body, created_by
+)
+SELECT $1, $2, coalesce(max(version), 0) + 1, $3, $4, $5, $6
+FROM oleander.notification_templates
+WHERE name = $1 AND locale = $2
+ON CONFLICT (name, locale, version) DO NOTHING
+RETURNING name, locale, version, format, subject, body, created_by, created_at;
\ No newline at end of file
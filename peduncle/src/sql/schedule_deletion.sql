@@ -0,0 +1,4 @@
+UPDATE oleander.users
+SET deletion_due_at = coalesce(deletion_due_at, now() + ($2::int * interval '1 hour'))
+WHERE lower(username) = lower($1) AND deleted_at IS NULL
+RETURNING username, email, deletion_due_at
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT username, first_name, last_name, labels, ts_rank(search_vector, query) AS rank
+FROM oleander.users, websearch_to_tsquery('simple', $1) AS query
+WHERE search_vector @@ query AND deleted_at IS NULL
+ORDER BY rank DESC, username
+LIMIT $2;
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT count(*) FROM oleander.users WHERE deleted_at IS NULL;
\ No newline at end of file
@@ -0,0 +1,3 @@
+SELECT name, role
+FROM oleander.service_accounts
+WHERE key_hash = $1;
\ No newline at end of file
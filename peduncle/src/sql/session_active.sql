@@ -0,0 +1,3 @@
+SELECT 1
+FROM oleander.sessions
+WHERE id = $1 AND revoked_at IS NULL AND expires_at > now()
\ No newline at end of file
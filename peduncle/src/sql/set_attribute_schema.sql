@@ -0,0 +1,4 @@
+INSERT INTO oleander.attribute_schema(id, schema)
+VALUES (1, $1)
+
+ON CONFLICT (id) DO UPDATE SET schema = EXCLUDED.schema, updated_at = now();
\ No newline at end of file
@@ -0,0 +1,4 @@
+INSERT INTO oleander.audit_forward_checkpoints (destination, last_id)
+VALUES ($1, $2)
+ON CONFLICT (destination) DO UPDATE
+SET last_id = EXCLUDED.last_id, updated_at = now();
\ No newline at end of file
@@ -0,0 +1,6 @@
+INSERT INTO oleander.cdc_checkpoints(consumer, last_txid, last_seq, last_lsn)
+VALUES ($1, $2::bigint::text::xid8, $3, $4::text::pg_lsn)
+
+ON CONFLICT (consumer) DO UPDATE
+SET last_txid = EXCLUDED.last_txid, last_seq = EXCLUDED.last_seq, last_lsn = EXCLUDED.last_lsn,
+    updated_at = now();
\ No newline at end of file
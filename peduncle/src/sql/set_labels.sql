@@ -0,0 +1,5 @@
+UPDATE oleander.users
+SET labels = ARRAY(SELECT DISTINCT unnest($2::text[]) ORDER BY 1), version = version + 1
+WHERE lower(username) = $1 AND deleted_at IS NULL
+
+RETURNING labels;
\ No newline at end of file
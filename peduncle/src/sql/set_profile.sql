@@ -0,0 +1,3 @@
+UPDATE oleander.users SET profile = $2, version = version + 1 WHERE lower(username) = $1 AND deleted_at IS NULL
+
+RETURNING profile;
\ No newline at end of file
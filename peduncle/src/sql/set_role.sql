@@ -0,0 +1 @@
+UPDATE oleander.users SET role = $2 WHERE lower(username) = $1 AND deleted_at IS NULL;
\ No newline at end of file
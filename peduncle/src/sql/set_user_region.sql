@@ -0,0 +1 @@
+UPDATE oleander.users SET region = $2 WHERE lower(username) = $1
\ No newline at end of file
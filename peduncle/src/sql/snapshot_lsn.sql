@@ -0,0 +1,3 @@
+-- On a standby the snapshot follows replay, not the standby's own WAL.
+SELECT CASE WHEN pg_is_in_recovery() THEN pg_last_wal_replay_lsn()
+            ELSE pg_current_wal_lsn() END::text
\ No newline at end of file
@@ -0,0 +1,9 @@
+CREATE TEMP TABLE import_users (
+    line        BIGINT NOT NULL,
+    username    VARCHAR(200) NOT NULL,
+    first_name  VARCHAR(200) NOT NULL,
+    last_name   VARCHAR(200) NOT NULL,
+    pwd         VARCHAR(200) NOT NULL,
+    username_skeleton VARCHAR(200) NOT NULL,
+    email       VARCHAR(320)
+) ON COMMIT DROP;
\ No newline at end of file
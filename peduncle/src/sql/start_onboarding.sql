@@ -0,0 +1,3 @@
+INSERT INTO oleander.onboarding_flows (id, expires_at)
+VALUES ($1, now() + ($2::int * interval '1 hour'))
+RETURNING id, steps, expires_at
\ No newline at end of file
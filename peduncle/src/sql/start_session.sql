@@ -0,0 +1,2 @@
+INSERT INTO oleander.sessions (id, tenant, username, expires_at)
+VALUES ($1, $2, $3, now() + ($4::int * interval '1 second'))
\ No newline at end of file
@@ -0,0 +1,5 @@
+SELECT id, username, first_name, last_name, labels
+FROM oleander.users
+WHERE id > $1 AND deleted_at IS NULL
+ORDER BY id
+LIMIT $2;
@@ -0,0 +1,6 @@
+UPDATE oleander.onboarding_flows
+SET steps = steps || jsonb_build_object($2::text, $3::jsonb),
+    updated_at = now(),
+    expires_at = now() + ($4::int * interval '1 hour')
+WHERE id = $1 AND completed_at IS NULL AND expires_at > now()
+RETURNING id, steps, expires_at
\ No newline at end of file
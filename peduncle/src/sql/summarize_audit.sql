@@ -0,0 +1,26 @@
+WITH purged AS (
+    DELETE FROM oleander.audit_events
+    WHERE id IN (
+        SELECT id FROM oleander.audit_events
+        WHERE created_at < now() - ($1::int * interval '1 day')
+        ORDER BY id
+        LIMIT $2
+    )
+    RETURNING actor, action, created_at
+), summarized AS (
+    INSERT INTO oleander.audit_summaries AS s (month, actor, action, events, first_at, last_at)
+    SELECT
+        date_trunc('month', created_at AT TIME ZONE 'UTC')::date,
+        actor,
+        action,
+        count(*),
+        min(created_at),
+        max(created_at)
+    FROM purged
+    GROUP BY 1, 2, 3
+    ON CONFLICT (month, actor, action) DO UPDATE SET
+        events = s.events + EXCLUDED.events,
+        first_at = least(s.first_at, EXCLUDED.first_at),
+        last_at = greatest(s.last_at, EXCLUDED.last_at)
+)
+SELECT count(*) FROM purged;
\ No newline at end of file
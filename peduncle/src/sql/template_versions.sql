@@ -0,0 +1,4 @@
+SELECT name, locale, version, format, subject, body, created_by, created_at
+FROM oleander.notification_templates
+WHERE name = $1 AND locale = $2
+ORDER BY version DESC;
\ No newline at end of file
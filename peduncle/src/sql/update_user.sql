@@ -0,0 +1,8 @@
+UPDATE oleander.users
+SET {assignments}
+WHERE lower(username) = $1
+    AND deleted_at IS NULL
+    AND ($2::bigint IS NULL OR version = $2)
+    AND ($3::text IS NULL OR lower(username) = $3)
+
+RETURNING username, first_name, last_name, labels, version, updated_at;
\ No newline at end of file
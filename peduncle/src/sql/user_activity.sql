@@ -0,0 +1,6 @@
+SELECT a.id, a.kind, a.detail, a.created_at
+FROM oleander.user_activity a
+JOIN oleander.users u ON u.id = a.user_id
+WHERE lower(u.username) = $1 AND ($2::bigint IS NULL OR a.id < $2)
+ORDER BY a.id DESC
+LIMIT $3;
\ No newline at end of file
@@ -0,0 +1,2 @@
+SELECT min(seq), max(seq)
+FROM oleander.user_changes
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Below the snapshot's xmin every transaction has ended, so no change can still
+-- commit and sort before one read here.
+SELECT seq, lsn::text, op, username, data, changed_at, txid::text::bigint
+FROM oleander.user_changes
+WHERE (txid, seq) > ($1::bigint::text::xid8, $2)
+  AND txid < pg_snapshot_xmin(pg_current_snapshot())
+ORDER BY txid, seq
+LIMIT $3;
\ No newline at end of file
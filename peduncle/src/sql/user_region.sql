@@ -0,0 +1 @@
+SELECT region FROM oleander.user_regions WHERE username = $1
\ No newline at end of file
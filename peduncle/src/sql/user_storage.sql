@@ -0,0 +1,2 @@
+SELECT profile, cardinality(labels) FROM oleander.users
+WHERE lower(username) = $1 AND deleted_at IS NULL
\ No newline at end of file
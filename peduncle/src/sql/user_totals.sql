@@ -0,0 +1,5 @@
+SELECT
+    count(*) AS total_users,
+    count(*) FILTER (WHERE last_active_at >= now() - interval '30 days') AS active_last_30_days
+FROM oleander.users
+WHERE deleted_at IS NULL;
\ No newline at end of file
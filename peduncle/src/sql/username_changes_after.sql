@@ -0,0 +1,17 @@
+SELECT seq, username, change, changed_at
+FROM (
+    SELECT DISTINCT ON (lower(username))
+        seq,
+        username,
+        CASE
+            WHEN op = 'delete' OR data->>'deleted_at' IS NOT NULL THEN 'deleted'
+            WHEN op = 'insert' THEN 'created'
+            ELSE 'updated'
+        END AS change,
+        changed_at
+    FROM oleander.user_changes
+    WHERE seq > $1
+    ORDER BY lower(username), seq DESC
+) latest
+ORDER BY seq
+LIMIT $2
\ No newline at end of file
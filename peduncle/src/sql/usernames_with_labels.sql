@@ -0,0 +1 @@
+SELECT username FROM oleander.users WHERE labels && $1::text[] AND deleted_at IS NULL;
\ No newline at end of file
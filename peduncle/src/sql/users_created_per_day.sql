@@ -0,0 +1,5 @@
+SELECT day::date, count(u.id)
+FROM generate_series(current_date - ($1::int - 1), current_date, interval '1 day') AS day
+LEFT JOIN oleander.users u ON u.created_at::date = day::date
+GROUP BY day
+ORDER BY day;
\ No newline at end of file
@@ -0,0 +1 @@
+SELECT max(updated_at) FROM oleander.users;
\ No newline at end of file